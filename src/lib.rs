@@ -1,15 +1,88 @@
-use std::{
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+extern crate std;
+
+use core::{
     mem::MaybeUninit,
-    ops::{Deref, DerefMut},
+    ops::{Bound, Deref, DerefMut, RangeBounds},
 };
 
+mod serde;
+mod string;
+
+pub use string::CopyArrayString;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A type that can be used as the length-storage field of a [`CopyArrayVec`]
+///
+/// Implemented for `u8`, `u16`, `u32`, and `usize` so that a vec whose
+/// capacity is known to fit in a smaller integer doesn't have to spend a
+/// full `usize` tracking its length. This trait is sealed and cannot be
+/// implemented outside this crate.
+///
+/// # Invariant
+/// A [`CopyArrayVec<T, MAX, L>`](CopyArrayVec) requires `MAX <= L::MAX`, so
+/// that every length in `0..=MAX` round-trips through [`Len::from_usize`]
+/// and [`Len::to_usize`] without truncation. This is enforced at
+/// monomorphization time; see [`CopyArrayVec::default`]. For example, this
+/// fails to compile because `300 > u8::MAX as usize`:
+///
+/// ```compile_fail
+/// # use copy_arrayvec::CopyArrayVec;
+/// let arr = CopyArrayVec::<u8, 300, u8>::new();
+/// ```
+pub trait Len: sealed::Sealed + Copy {
+    /// The largest value representable by this length type
+    const MAX: usize;
+    #[doc(hidden)]
+    fn to_usize(self) -> usize;
+    #[doc(hidden)]
+    fn from_usize(n: usize) -> Self;
+}
+
+macro_rules! impl_len {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl Len for $t {
+                const MAX: usize = <$t>::MAX as usize;
+
+                fn to_usize(self) -> usize {
+                    self as usize
+                }
+                fn from_usize(n: usize) -> Self {
+                    debug_assert!(n <= <Self as Len>::MAX, "CopyArrayVec length overflowed its Len storage type");
+                    n as $t
+                }
+            }
+        )+
+    };
+}
+impl_len!(u8, u16, u32, usize);
+
 #[derive(Clone, Copy)]
-pub struct CopyArrayVec<T: Copy, const MAX: usize> {
+pub struct CopyArrayVec<T: Copy, const MAX: usize, L: Len = usize> {
     buf: [MaybeUninit<T>; MAX],
-    len: usize,
+    len: L,
 }
-impl<T: Copy + std::fmt::Debug, const MAX: usize> std::fmt::Debug for CopyArrayVec<T, MAX> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+/// Error returned when an operation would exceed a [`CopyArrayVec`]'s capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+impl core::error::Error for CapacityError {}
+impl<T: Copy + core::fmt::Debug, const MAX: usize, L: Len> core::fmt::Debug for CopyArrayVec<T, MAX, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("CopyArrayVec")
             .field("max", &MAX)
             .field("buf", &self.deref())
@@ -17,26 +90,41 @@ impl<T: Copy + std::fmt::Debug, const MAX: usize> std::fmt::Debug for CopyArrayV
     }
 }
 
-impl<T: Copy, const MAX: usize> Default for CopyArrayVec<T, MAX> {
+impl<T: Copy, const MAX: usize, L: Len> Default for CopyArrayVec<T, MAX, L> {
+    /// # Panics (at compile time)
+    /// If `MAX` does not fit in `L` (see the [`Len`] invariant). This is
+    /// checked wherever a `CopyArrayVec<T, MAX, L>` is actually monomorphized,
+    /// e.g. via [`CopyArrayVec::new`]
     fn default() -> Self {
+        const { assert!(MAX <= L::MAX, "CopyArrayVec: MAX does not fit in L, shrink MAX or widen L") };
         Self {
             buf: unsafe { MaybeUninit::uninit().assume_init() },
-            len: 0,
+            len: L::from_usize(0),
         }
     }
 }
 
-impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
+impl<T: Copy, const MAX: usize, L: Len> CopyArrayVec<T, MAX, L> {
     pub fn new() -> Self {
         Self::default()
     }
     /// Get the length
-    pub const fn len(&self) -> usize {
-        self.len
+    ///
+    /// # Note
+    /// This is no longer a `const fn` now that the length is stored as a
+    /// generic [`Len`] rather than a concrete `usize`: `Len::to_usize` is a
+    /// regular trait method, and const trait calls aren't supported on
+    /// stable Rust. [`CopyArrayVec::capacity`] is unaffected and stays const
+    /// since it only depends on `MAX`.
+    pub fn len(&self) -> usize {
+        self.len.to_usize()
     }
     /// Check if empty
-    pub const fn is_empty(&self) -> bool {
-        self.len == 0
+    ///
+    /// # Note
+    /// No longer a `const fn`, for the same reason as [`CopyArrayVec::len`]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
     /// Push a new element
     ///
@@ -56,9 +144,9 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     pub fn push(&mut self, el: T) {
         assert!(self.len() < MAX, "tried to push to full arrayvec");
 
-        let next = self.len;
+        let next = self.len();
         self.buf[next].write(el);
-        self.len += 1;
+        self.len = L::from_usize(next + 1);
     }
 
     /// Attempt to push a new element
@@ -71,7 +159,6 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     /// arr.push(5);
     /// assert_eq!(arr.try_push(0), Err(0));
     /// ```
-
     pub fn try_push(&mut self, el: T) -> Result<(), T> {
         if self.capacity_remaining() > 0 {
             self.push(el);
@@ -93,7 +180,7 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
         if self.is_empty() {
             None
         } else {
-            Some(self.remove(self.len - 1))
+            Some(self.remove(self.len() - 1))
         }
     }
     /// Remove an element from a specific position
@@ -126,11 +213,12 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     /// but it is a single memcpy in reality due to the [`Copy`] bound
     pub fn remove(&mut self, i: usize) -> T {
         let v = self[i];
+        let len = self.len();
         unsafe {
             let buf_p = self.buf.as_mut_ptr().add(i);
-            std::ptr::copy(buf_p.add(1).cast_const(), buf_p, self.len - i)
+            core::ptr::copy(buf_p.add(1).cast_const(), buf_p, len - i - 1)
         }
-        self.len -= 1;
+        self.len = L::from_usize(len - 1);
         v
     }
     /// Insert an element at a specific position
@@ -155,14 +243,17 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     /// Has the same complexity bounds as [`CopyArrayVec::remove`]
     pub fn insert(&mut self, i: usize, value: T) {
         assert!(!self.is_full(), "tried to insert into a full CopyArrayVec");
-        if i == self.len() {
+        let len = self.len();
+        assert!(i <= len, "insertion index out of bounds");
+        if i == len {
             self.push(value);
         } else {
             unsafe {
                 let buf_p = self.buf.as_mut_ptr().add(i);
-                std::ptr::copy(buf_p.cast_const(), buf_p.add(1), self.len - i);
+                core::ptr::copy(buf_p.cast_const(), buf_p.add(1), len - i);
             }
-            self.len += 1;
+            self.buf[i].write(value);
+            self.len = L::from_usize(len + 1);
         }
     }
 
@@ -177,7 +268,6 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     ///
     /// # Panics
     /// If `i` is out of bounds
-
     pub fn try_insert(&mut self, i: usize, value: T) -> Result<(), T> {
         if self.is_full() {
             Err(value)
@@ -195,7 +285,10 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     /// arr.push(2);
     /// assert_eq!(arr.capacity_remaining(), 4);
     /// ```
-    pub const fn capacity_remaining(&self) -> usize {
+    ///
+    /// # Note
+    /// No longer a `const fn`, for the same reason as [`CopyArrayVec::len`]
+    pub fn capacity_remaining(&self) -> usize {
         MAX - self.len()
     }
 
@@ -209,7 +302,7 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     /// arr.push(1);
     /// assert!(arr.is_full());
     /// ```
-    pub const fn is_full(&self) -> bool {
+    pub fn is_full(&self) -> bool {
         self.capacity_remaining() == 0
     }
     /// The max capacity of the [`CopyArrayVec`]
@@ -241,24 +334,246 @@ impl<T: Copy, const MAX: usize> CopyArrayVec<T, MAX> {
     pub fn clear(&mut self) {
         // this is trivial because we know that `T` does not require drop we can just
         // reset our write head
-        self.len = 0;
+        self.len = L::from_usize(0);
     }
+
+    /// Copy all elements of `other` onto the end of this [`CopyArrayVec`]
+    ///
+    /// # Panics
+    /// If `other` does not fit in the remaining capacity
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let mut arr = CopyArrayVec::<_, 5>::new();
+    /// arr.push(1);
+    /// arr.extend_from_slice(&[2, 3, 4]);
+    /// assert_eq!(&arr[..], &[1, 2, 3, 4]);
+    /// ```
+    ///
+    /// # Complexity
+    /// O(n), a single memcpy
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.try_extend_from_slice(other)
+            .expect("not enough capacity to extend from slice");
+    }
+
+    /// Try to copy all elements of `other` onto the end of this [`CopyArrayVec`]
+    ///
+    /// This will return an Err and leave `self` unchanged if `other` does not
+    /// fit in the remaining capacity
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let mut arr = CopyArrayVec::<_, 2>::new();
+    /// assert!(arr.try_extend_from_slice(&[1, 2, 3]).is_err());
+    /// assert_eq!(arr.len(), 0);
+    /// ```
+    ///
+    /// # Complexity
+    /// O(n), a single memcpy
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError> {
+        if other.len() > self.capacity_remaining() {
+            return Err(CapacityError);
+        }
+        let len = self.len();
+        unsafe {
+            core::ptr::copy_nonoverlapping(other.as_ptr(), self.buf.as_mut_ptr().add(len).cast(), other.len());
+        }
+        self.len = L::from_usize(len + other.len());
+        Ok(())
+    }
+
+    /// Remove and yield the elements in `range`, shifting the untouched tail
+    /// down to close the gap once the returned [`Drain`] is dropped
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let mut arr = CopyArrayVec::<_, 5>::new();
+    /// arr.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// let drained: Vec<_> = arr.drain(1..3).collect();
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert_eq!(&arr[..], &[1, 4, 5]);
+    /// ```
+    ///
+    /// # Panics
+    /// If the start of the range is after its end, or the end is out of bounds
+    ///
+    /// ```should_panic
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let mut arr = CopyArrayVec::<_, 5>::new();
+    /// arr.extend_from_slice(&[1, 2, 3]);
+    /// arr.drain(0..4);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, MAX, L> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "drain start is after drain end");
+        assert!(end <= len, "drain end is out of bounds");
+
+        Drain {
+            start,
+            idx: start,
+            end,
+            tail_len: len - end,
+            vec: self,
+        }
+    }
+
+    /// Remove an element by swapping it with the last element, then
+    /// shrinking the length
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let mut arr = CopyArrayVec::<_, 5>::new();
+    /// arr.extend_from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(arr.swap_remove(0), 1);
+    /// assert_eq!(&arr[..], &[4, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    /// If `i` is out of bounds
+    ///
+    /// # Complexity
+    /// O(1), unlike [`CopyArrayVec::remove`]
+    pub fn swap_remove(&mut self, i: usize) -> T {
+        let len = self.len();
+        assert!(i < len, "swap_remove index out of bounds");
+        let v = self[i];
+        self.buf[i] = self.buf[len - 1];
+        self.len = L::from_usize(len - 1);
+        v
+    }
+
+    /// Shorten the vec to its first `n` elements, removing the rest
+    ///
+    /// Does nothing if `n >= len()`
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let mut arr = CopyArrayVec::<_, 5>::new();
+    /// arr.extend_from_slice(&[1, 2, 3, 4]);
+    /// arr.truncate(2);
+    /// assert_eq!(&arr[..], &[1, 2]);
+    /// ```
+    ///
+    /// # Complexity
+    /// O(1) since nothing needs to be dropped
+    pub fn truncate(&mut self, n: usize) {
+        if n < self.len() {
+            self.len = L::from_usize(n);
+        }
+    }
+
+    /// Keep only the elements for which `f` returns `true`, compacting the
+    /// kept elements toward the front in a single pass
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let mut arr = CopyArrayVec::<_, 5>::new();
+    /// arr.extend_from_slice(&[1, 2, 3, 4, 5]);
+    /// arr.retain(|&x| x % 2 == 0);
+    /// assert_eq!(&arr[..], &[2, 4]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let len = self.len();
+        let mut write = 0;
+        for read in 0..len {
+            let v = self[read];
+            if f(&v) {
+                self.buf[write] = self.buf[read];
+                write += 1;
+            }
+        }
+        self.len = L::from_usize(write);
+    }
+}
+
+/// A draining iterator over a [`CopyArrayVec`]
+///
+/// This struct is created by [`CopyArrayVec::drain`]. See its documentation for more.
+///
+/// Because `T: Copy`, no elements ever need to be dropped: the tail shift
+/// that closes the gap left by the drained range happens unconditionally in
+/// `Drop`. A leaked or forgotten [`Drain`] never runs that shift, so the vec
+/// keeps its original length and the drained elements stay in it, logically
+/// duplicating whatever was yielded, rather than causing undefined behaviour.
+pub struct Drain<'a, T: Copy, const MAX: usize, L: Len = usize> {
+    vec: &'a mut CopyArrayVec<T, MAX, L>,
+    start: usize,
+    idx: usize,
+    end: usize,
+    tail_len: usize,
 }
 
-impl<T: Copy, const MAX: usize> Deref for CopyArrayVec<T, MAX> {
+impl<T: Copy, const MAX: usize, L: Len> Iterator for Drain<'_, T, MAX, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            let v = unsafe { self.vec.buf[self.idx].assume_init() };
+            self.idx += 1;
+            Some(v)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T: Copy, const MAX: usize, L: Len> DoubleEndedIterator for Drain<'_, T, MAX, L> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { self.vec.buf[self.end].assume_init() })
+        }
+    }
+}
+
+impl<T: Copy, const MAX: usize, L: Len> ExactSizeIterator for Drain<'_, T, MAX, L> {}
+
+impl<T: Copy, const MAX: usize, L: Len> Drop for Drain<'_, T, MAX, L> {
+    fn drop(&mut self) {
+        let vec_len = self.vec.len();
+        let tail_start = vec_len - self.tail_len;
+        if self.tail_len > 0 {
+            unsafe {
+                let buf_p = self.vec.buf.as_mut_ptr();
+                core::ptr::copy(buf_p.add(tail_start).cast_const(), buf_p.add(self.start), self.tail_len);
+            }
+        }
+        self.vec.len = L::from_usize(self.start + self.tail_len);
+    }
+}
+
+impl<T: Copy, const MAX: usize, L: Len> Deref for CopyArrayVec<T, MAX, L> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
-        unsafe { std::slice::from_raw_parts(self.buf.as_ptr().cast(), self.len()) }
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr().cast(), self.len()) }
     }
 }
 
-impl<T: Copy, const MAX: usize> DerefMut for CopyArrayVec<T, MAX> {
+impl<T: Copy, const MAX: usize, L: Len> DerefMut for CopyArrayVec<T, MAX, L> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { std::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast(), self.len()) }
+        unsafe { core::slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast(), self.len()) }
     }
 }
-impl<T: Copy, const MAX: usize> Extend<T> for CopyArrayVec<T, MAX> {
+impl<T: Copy, const MAX: usize, L: Len> Extend<T> for CopyArrayVec<T, MAX, L> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for item in iter {
             self.push(item);
@@ -266,20 +581,20 @@ impl<T: Copy, const MAX: usize> Extend<T> for CopyArrayVec<T, MAX> {
     }
 }
 
-impl<T: Copy + PartialEq, const MAX: usize> PartialEq for CopyArrayVec<T, MAX> {
+impl<T: Copy + PartialEq, const MAX: usize, L: Len> PartialEq for CopyArrayVec<T, MAX, L> {
     fn eq(&self, other: &Self) -> bool {
         self.deref() == other.deref()
     }
 }
-impl<T: Copy + Eq, const MAX: usize> Eq for CopyArrayVec<T, MAX> {}
+impl<T: Copy + Eq, const MAX: usize, L: Len> Eq for CopyArrayVec<T, MAX, L> {}
 
-impl<T: Copy + std::hash::Hash, const MAX: usize> std::hash::Hash for CopyArrayVec<T, MAX> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl<T: Copy + core::hash::Hash, const MAX: usize, L: Len> core::hash::Hash for CopyArrayVec<T, MAX, L> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.deref().hash(state)
     }
 }
 
-impl<T: Copy, const MAX: usize> FromIterator<T> for CopyArrayVec<T, MAX> {
+impl<T: Copy, const MAX: usize, L: Len> FromIterator<T> for CopyArrayVec<T, MAX, L> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut me = Self::default();
         for item in iter {
@@ -289,11 +604,74 @@ impl<T: Copy, const MAX: usize> FromIterator<T> for CopyArrayVec<T, MAX> {
     }
 }
 
+impl<T: Copy, const MAX: usize, L: Len> IntoIterator for CopyArrayVec<T, MAX, L> {
+    type Item = T;
+    type IntoIter = CopyArrayIntoIter<T, MAX, L>;
+
+    /// Consume `self`, yielding its elements by value
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayVec;
+    /// let arr = (0..3).collect::<CopyArrayVec<_, 3>>();
+    /// let collected: Vec<_> = arr.into_iter().collect();
+    /// assert_eq!(collected, vec![0, 1, 2]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let tail = self.len();
+        CopyArrayIntoIter {
+            vec: self,
+            head: 0,
+            tail,
+        }
+    }
+}
+
+/// A consuming iterator over a [`CopyArrayVec`], yielding elements by value
+///
+/// This struct is created by the [`IntoIterator`] impl for [`CopyArrayVec`]
+pub struct CopyArrayIntoIter<T: Copy, const MAX: usize, L: Len = usize> {
+    vec: CopyArrayVec<T, MAX, L>,
+    head: usize,
+    tail: usize,
+}
+
+impl<T: Copy, const MAX: usize, L: Len> Iterator for CopyArrayIntoIter<T, MAX, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.head == self.tail {
+            None
+        } else {
+            let v = unsafe { self.vec.buf[self.head].assume_init() };
+            self.head += 1;
+            Some(v)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.tail - self.head;
+        (len, Some(len))
+    }
+}
+
+impl<T: Copy, const MAX: usize, L: Len> DoubleEndedIterator for CopyArrayIntoIter<T, MAX, L> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.head == self.tail {
+            None
+        } else {
+            self.tail -= 1;
+            Some(unsafe { self.vec.buf[self.tail].assume_init() })
+        }
+    }
+}
+
+impl<T: Copy, const MAX: usize, L: Len> ExactSizeIterator for CopyArrayIntoIter<T, MAX, L> {}
+
 #[cfg(test)]
 mod tests {
-    use std::ops::Deref;
+    use std::{ops::Deref, vec, vec::Vec};
 
-    use crate::CopyArrayVec;
+    use crate::{CapacityError, CopyArrayVec};
 
     fn upto_vec<const M: usize>() -> CopyArrayVec<usize, M> {
         (0..M).collect()
@@ -345,6 +723,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extend_from_slice_copies_in_one_go() {
+        let mut arr = CopyArrayVec::<_, 5>::new();
+        arr.push(1);
+        arr.extend_from_slice(&[2, 3, 4]);
+        assert_eq!(&arr[..], &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn try_extend_from_slice_fails_without_writing() {
+        let mut arr = CopyArrayVec::<_, 2>::new();
+        arr.push(1);
+        assert_eq!(arr.try_extend_from_slice(&[2, 3]), Err(CapacityError));
+        assert_eq!(&arr[..], &[1]);
+    }
+
+    #[test]
+    fn drain_removes_and_yields_range() {
+        let mut arr = upto_vec::<10>();
+        let drained: Vec<_> = arr.drain(2..5).collect();
+        assert_eq!(drained, vec![2, 3, 4]);
+        assert_eq!(&arr[..], &[0, 1, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn drain_rev_yields_in_reverse() {
+        let mut arr = upto_vec::<5>();
+        let drained: Vec<_> = arr.drain(1..4).rev().collect();
+        assert_eq!(drained, vec![3, 2, 1]);
+        assert_eq!(&arr[..], &[0, 4]);
+    }
+
+    #[test]
+    fn drain_dropped_without_iterating_still_closes_gap() {
+        let mut arr = upto_vec::<5>();
+        arr.drain(1..3);
+        assert_eq!(&arr[..], &[0, 3, 4]);
+    }
+
+    #[test]
+    fn into_iter_yields_by_value() {
+        let arr = upto_vec::<5>();
+        let collected: Vec<_> = arr.into_iter().collect();
+        assert_eq!(collected, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn into_iter_rev_yields_in_reverse() {
+        let arr = upto_vec::<5>();
+        let collected: Vec<_> = arr.into_iter().rev().collect();
+        assert_eq!(collected, vec![4, 3, 2, 1, 0]);
+    }
+
     #[test]
     fn remove_at_start() {
         let mut arr = upto_vec::<10>();
@@ -358,4 +789,59 @@ mod tests {
                 .collect::<CopyArrayVec<_, 10>>()
         );
     }
+
+    #[test]
+    fn remove_last_of_full_vec_does_not_read_past_the_end() {
+        let mut arr = upto_vec::<10>();
+        assert!(arr.is_full());
+        arr.remove(9);
+        assert_eq!(
+            arr,
+            upto_vec::<10>()
+                .iter()
+                .take(9)
+                .copied()
+                .collect::<CopyArrayVec<_, 10>>()
+        );
+    }
+
+    #[test]
+    fn swap_remove_moves_last_into_gap() {
+        let mut arr = upto_vec::<5>();
+        assert_eq!(arr.swap_remove(1), 1);
+        assert_eq!(&arr[..], &[0, 4, 2, 3]);
+    }
+
+    #[test]
+    fn truncate_shrinks_len() {
+        let mut arr = upto_vec::<5>();
+        arr.truncate(2);
+        assert_eq!(&arr[..], &[0, 1]);
+        arr.truncate(10);
+        assert_eq!(&arr[..], &[0, 1]);
+    }
+
+    #[test]
+    fn retain_compacts_kept_elements() {
+        let mut arr = upto_vec::<6>();
+        arr.retain(|&x| x % 2 == 0);
+        assert_eq!(&arr[..], &[0, 2, 4]);
+    }
+
+    #[test]
+    fn u8_len_vec_behaves_like_usize_len_vec() {
+        let mut arr = CopyArrayVec::<_, 4, u8>::new();
+        arr.push(1);
+        arr.push(2);
+        assert_eq!(arr.len(), 2);
+        assert_eq!(&arr[..], &[1, 2]);
+        assert!(std::mem::size_of::<CopyArrayVec<u8, 4, u8>>() < std::mem::size_of::<CopyArrayVec<u8, 4, usize>>());
+    }
+
+    #[test]
+    fn max_exactly_fitting_in_l_is_allowed() {
+        let mut arr = CopyArrayVec::<_, 255, u8>::new();
+        arr.extend_from_slice(&[1; 255]);
+        assert_eq!(arr.len(), 255);
+    }
 }
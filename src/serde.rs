@@ -1,10 +1,10 @@
-use std::{marker::PhantomData, ops::Deref};
+use core::{marker::PhantomData, ops::Deref};
 
 use serde::{de::Visitor, Deserialize, Serialize};
 
-use crate::CopyArrayVec;
+use crate::{CopyArrayVec, Len};
 
-impl<T: Copy + Serialize, const C: usize> Serialize for CopyArrayVec<T, C> {
+impl<T: Copy + Serialize, const C: usize, L: Len> Serialize for CopyArrayVec<T, C, L> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -13,17 +13,17 @@ impl<T: Copy + Serialize, const C: usize> Serialize for CopyArrayVec<T, C> {
     }
 }
 
-impl<'de, T: Copy + Deserialize<'de>, const C: usize> Deserialize<'de> for CopyArrayVec<T, C> {
+impl<'de, T: Copy + Deserialize<'de>, const C: usize, L: Len> Deserialize<'de> for CopyArrayVec<T, C, L> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        struct Visit<T, const C: usize>(PhantomData<fn() -> T>);
-        impl<'de, T: Copy + Deserialize<'de>, const C: usize> Visitor<'de> for Visit<T, C> {
-            type Value = CopyArrayVec<T, C>;
+        struct Visit<T, const C: usize, L: Len>(PhantomData<fn() -> (T, L)>);
+        impl<'de, T: Copy + Deserialize<'de>, const C: usize, L: Len> Visitor<'de> for Visit<T, C, L> {
+            type Value = CopyArrayVec<T, C, L>;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("an array of max length {C}")
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(formatter, "an array of max length {C}")
             }
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
             where
@@ -0,0 +1,252 @@
+use core::{fmt, hash::Hash, ops::Deref, str::FromStr};
+
+use crate::{CapacityError, CopyArrayVec};
+
+/// A fixed-capacity, stack-allocated, `Copy`-able UTF-8 string
+///
+/// Backed by a [`CopyArrayVec<u8, MAX>`](CopyArrayVec), analogous to
+/// arrayvec's `ArrayString`. Useful for small keys and labels where a heap
+/// `String` is overkill.
+#[derive(Clone, Copy, Default)]
+pub struct CopyArrayString<const MAX: usize> {
+    buf: CopyArrayVec<u8, MAX>,
+}
+
+impl<const MAX: usize> CopyArrayString<MAX> {
+    /// Create a new, empty [`CopyArrayString`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Borrow the contents as a `&str`
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayString;
+    /// let mut s = CopyArrayString::<5>::new();
+    /// s.push_str("hi");
+    /// assert_eq!(s.as_str(), "hi");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        // SAFETY: every write to `buf` goes through `try_push_str`, which
+        // only ever appends a complete `&str`, so `buf` is always valid UTF-8
+        unsafe { core::str::from_utf8_unchecked(&self.buf) }
+    }
+
+    /// Append a `&str` to the end of this [`CopyArrayString`]
+    ///
+    /// # Panics
+    /// If `s` does not fit in the remaining capacity
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayString;
+    /// let mut s = CopyArrayString::<5>::new();
+    /// s.push_str("hi");
+    /// assert_eq!(s.as_str(), "hi");
+    /// ```
+    pub fn push_str(&mut self, s: &str) {
+        self.try_push_str(s)
+            .expect("not enough capacity to push_str");
+    }
+
+    /// Try to append a `&str` to the end of this [`CopyArrayString`]
+    ///
+    /// Returns an Err and leaves `self` unchanged if `s` does not fit
+    /// entirely in the remaining capacity; a partial write never happens,
+    /// so the UTF-8 invariant always holds.
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayString;
+    /// let mut s = CopyArrayString::<2>::new();
+    /// assert!(s.try_push_str("too long").is_err());
+    /// assert_eq!(s.as_str(), "");
+    /// ```
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        self.buf.try_extend_from_slice(s.as_bytes())
+    }
+
+    /// Append a single `char` to the end of this [`CopyArrayString`]
+    ///
+    /// # Panics
+    /// If the encoded `char` does not fit in the remaining capacity
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayString;
+    /// let mut s = CopyArrayString::<2>::new();
+    /// s.push('h');
+    /// assert_eq!(s.as_str(), "h");
+    /// ```
+    pub fn push(&mut self, c: char) {
+        self.try_push(c).expect("not enough capacity to push char");
+    }
+
+    /// Try to append a single `char` to the end of this [`CopyArrayString`]
+    ///
+    /// ```
+    /// # use copy_arrayvec::CopyArrayString;
+    /// let mut s = CopyArrayString::<1>::new();
+    /// assert!(s.try_push('€').is_err());
+    /// assert_eq!(s.as_str(), "");
+    /// ```
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError> {
+        let mut utf8_buf = [0u8; 4];
+        self.try_push_str(c.encode_utf8(&mut utf8_buf))
+    }
+
+    /// The length in bytes
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// The max capacity in bytes
+    pub const fn capacity(&self) -> usize {
+        MAX
+    }
+}
+
+impl<const MAX: usize> Deref for CopyArrayString<MAX> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const MAX: usize> fmt::Display for CopyArrayString<MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const MAX: usize> fmt::Debug for CopyArrayString<MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const MAX: usize> PartialEq for CopyArrayString<MAX> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl<const MAX: usize> Eq for CopyArrayString<MAX> {}
+
+impl<const MAX: usize> PartialEq<str> for CopyArrayString<MAX> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const MAX: usize> Hash for CopyArrayString<MAX> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const MAX: usize> FromStr for CopyArrayString<MAX> {
+    type Err = CapacityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut me = Self::default();
+        me.try_push_str(s)?;
+        Ok(me)
+    }
+}
+
+impl<const MAX: usize> TryFrom<&str> for CopyArrayString<MAX> {
+    type Error = CapacityError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const MAX: usize> serde::Serialize for CopyArrayString<MAX> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const MAX: usize> serde::Deserialize<'de> for CopyArrayString<MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visit<const MAX: usize>;
+        impl<'de, const MAX: usize> serde::de::Visitor<'de> for Visit<MAX> {
+            type Value = CopyArrayString<MAX>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(formatter, "a string of max length {MAX}")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                v.parse()
+                    .map_err(|_| E::invalid_length(v.len(), &"fewer bytes in string"))
+            }
+        }
+        deserializer.deserialize_str(Visit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use crate::CopyArrayString;
+
+    #[test]
+    fn push_and_push_str() {
+        let mut s = CopyArrayString::<5>::new();
+        s.push('h');
+        s.push_str("i!");
+        assert_eq!(s.as_str(), "hi!");
+    }
+
+    #[test]
+    fn try_push_str_rejects_partial_write() {
+        let mut s = CopyArrayString::<2>::new();
+        assert!(s.try_push_str("too long").is_err());
+        assert_eq!(s.as_str(), "");
+    }
+
+    #[test]
+    fn try_push_rejects_char_that_does_not_fit() {
+        let mut s = CopyArrayString::<1>::new();
+        assert!(s.try_push('€').is_err());
+        assert_eq!(s.as_str(), "");
+    }
+
+    #[test]
+    fn from_str_and_try_from() {
+        let s: CopyArrayString<5> = "hi".parse().unwrap();
+        assert_eq!(s.as_str(), "hi");
+        assert_eq!(CopyArrayString::<1>::try_from("too long"), Err(crate::CapacityError));
+    }
+
+    #[test]
+    fn serialize_as_string() {
+        let s = CopyArrayString::<5>::from_str("hi").unwrap();
+        assert_tokens(&s, &[Token::Str("hi")]);
+    }
+
+    #[test]
+    fn fails_to_deserialize_too_large() {
+        assert_de_tokens_error::<CopyArrayString<1>>(
+            &[Token::Str("hi")],
+            "invalid length 2, expected fewer bytes in string",
+        );
+    }
+}